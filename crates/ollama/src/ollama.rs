@@ -376,6 +376,52 @@ pub async fn show_model(
     Ok(details)
 }
 
+#[derive(Serialize)]
+struct EmbeddingRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+}
+
+#[derive(Deserialize)]
+pub struct EmbeddingResponse {
+    pub embedding: Vec<f32>,
+}
+
+pub async fn embed(
+    client: &dyn HttpClient,
+    api_url: &str,
+    api_key: Option<&str>,
+    model: &str,
+    text: &str,
+) -> Result<EmbeddingResponse> {
+    let uri = format!("{api_url}/api/embeddings");
+    let request = HttpRequest::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .when_some(api_key, |builder, api_key| {
+            builder.header("Authorization", format!("Bearer {api_key}"))
+        })
+        .body(AsyncBody::from(serde_json::to_string(&EmbeddingRequest {
+            model,
+            prompt: text,
+        })?))?;
+
+    let mut response = client.send(request).await.with_context(|| {
+        format!("Failed to connect to Ollama at {api_url}; is the Ollama daemon running?")
+    })?;
+    let mut body = String::new();
+    response.body_mut().read_to_string(&mut body).await?;
+
+    anyhow::ensure!(
+        response.status().is_success(),
+        "Failed to connect to Ollama API: {} {}",
+        response.status(),
+        body,
+    );
+    serde_json::from_str(&body).context("Unable to parse Ollama embedding response")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,6 +480,50 @@ mod tests {
         let _: ChatResponseDelta = serde_json::from_value(last).unwrap();
     }
 
+    #[test]
+    fn parse_embedding() {
+        let response = serde_json::json!({
+            "embedding": [0.5670403838157654, 0.009260174818336964, -0.2041469812393188]
+        });
+        let response: EmbeddingResponse = serde_json::from_value(response).unwrap();
+        assert_eq!(response.embedding.len(), 3);
+    }
+
+    #[test]
+    fn embed_reports_unreachable_daemon() {
+        let client = http_client::FakeHttpClient::create(|_| async move {
+            Err::<http_client::Response<AsyncBody>, _>(anyhow::anyhow!("Connection refused"))
+        });
+        let error = futures::executor::block_on(embed(
+            &*client,
+            OLLAMA_API_URL,
+            None,
+            "nomic-embed-text",
+            "fn main() {}",
+        ))
+        .err()
+        .unwrap();
+        assert!(
+            format!("{error:#}").contains("is the Ollama daemon running?"),
+            "unexpected error: {error:#}"
+        );
+    }
+
+    #[test]
+    fn serialize_embedding_request() {
+        let request = EmbeddingRequest {
+            model: "nomic-embed-text",
+            prompt: "fn main() {}",
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "model": "nomic-embed-text",
+                "prompt": "fn main() {}"
+            })
+        );
+    }
+
     #[test]
     fn parse_tool_call() {
         let response = serde_json::json!({
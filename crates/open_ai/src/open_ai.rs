@@ -540,10 +540,32 @@ pub enum OpenAiEmbeddingModel {
     TextEmbedding3Large,
 }
 
+impl OpenAiEmbeddingModel {
+    pub fn max_dimensions(&self) -> u64 {
+        match self {
+            Self::TextEmbedding3Small => 1536,
+            Self::TextEmbedding3Large => 3072,
+        }
+    }
+
+    fn validate_dimensions(&self, dimensions: Option<u64>) -> Result<()> {
+        if let Some(dimensions) = dimensions {
+            anyhow::ensure!(
+                (1..=self.max_dimensions()).contains(&dimensions),
+                "invalid embedding dimensions {dimensions}, expected 1..={}",
+                self.max_dimensions()
+            );
+        }
+        Ok(())
+    }
+}
+
 #[derive(Serialize)]
 struct OpenAiEmbeddingRequest<'a> {
     model: OpenAiEmbeddingModel,
     input: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dimensions: Option<u64>,
 }
 
 #[derive(Deserialize)]
@@ -561,22 +583,26 @@ pub fn embed<'a>(
     api_url: &str,
     api_key: &str,
     model: OpenAiEmbeddingModel,
+    dimensions: Option<u64>,
     texts: impl IntoIterator<Item = &'a str>,
 ) -> impl 'static + Future<Output = Result<OpenAiEmbeddingResponse>> {
     let uri = format!("{api_url}/embeddings");
 
-    let request = OpenAiEmbeddingRequest {
-        model,
-        input: texts.into_iter().collect(),
-    };
-    let body = AsyncBody::from(serde_json::to_string(&request).unwrap());
-    let request = HttpRequest::builder()
-        .method(Method::POST)
-        .uri(uri)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", api_key.trim()))
-        .body(body)
-        .map(|request| client.send(request));
+    let request = model.validate_dimensions(dimensions).and_then(|()| {
+        let request = OpenAiEmbeddingRequest {
+            model,
+            input: texts.into_iter().collect(),
+            dimensions,
+        };
+        let body = AsyncBody::from(serde_json::to_string(&request)?);
+        Ok(HttpRequest::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .header("Authorization", format!("Bearer {}", api_key.trim()))
+            .body(body)
+            .map(|request| client.send(request))?)
+    });
 
     async move {
         let mut response = request?.await?;
@@ -594,3 +620,79 @@ pub fn embed<'a>(
         Ok(response)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_client::{FakeHttpClient, Response};
+
+    fn embed_with_dimensions(dimensions: Option<u64>) -> Value {
+        let (request_tx, request_rx) = std::sync::mpsc::channel();
+        let client = FakeHttpClient::create(move |request| {
+            let mut body = request.into_body();
+            let request_tx = request_tx.clone();
+            async move {
+                let mut request_body = String::new();
+                body.read_to_string(&mut request_body).await?;
+                request_tx.send(serde_json::from_str::<Value>(&request_body)?)?;
+                Ok(Response::builder().status(200).body(AsyncBody::from(
+                    serde_json::json!({ "data": [{ "embedding": [0.25, -0.5] }] }).to_string(),
+                ))?)
+            }
+        });
+
+        let response = futures::executor::block_on(embed(
+            &*client,
+            OPEN_AI_API_URL,
+            "key",
+            OpenAiEmbeddingModel::TextEmbedding3Small,
+            dimensions,
+            ["fn main() {}"],
+        ))
+        .unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].embedding, vec![0.25, -0.5]);
+
+        request_rx.try_recv().unwrap()
+    }
+
+    #[test]
+    fn embed_sends_requested_dimensions() {
+        let request = embed_with_dimensions(Some(256));
+        assert_eq!(request["dimensions"], 256);
+        assert_eq!(request["model"], "text-embedding-3-small");
+    }
+
+    #[test]
+    fn embed_omits_dimensions_when_unset() {
+        let request = embed_with_dimensions(None);
+        assert!(request.get("dimensions").is_none());
+    }
+
+    #[test]
+    fn embed_rejects_unsupported_dimensions() {
+        let client = FakeHttpClient::create(|_| async move {
+            Err::<Response<AsyncBody>, _>(anyhow!(
+                "no request should be sent for invalid dimensions"
+            ))
+        });
+        let model = OpenAiEmbeddingModel::TextEmbedding3Large;
+
+        for dimensions in [0, model.max_dimensions() + 1] {
+            let error = futures::executor::block_on(embed(
+                &*client,
+                OPEN_AI_API_URL,
+                "key",
+                model,
+                Some(dimensions),
+                ["fn main() {}"],
+            ))
+            .err()
+            .unwrap();
+            assert!(
+                error.to_string().contains("invalid embedding dimensions"),
+                "unexpected error: {error}"
+            );
+        }
+    }
+}